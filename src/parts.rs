@@ -84,18 +84,6 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
     /// This does **not** mean that the ARN itself is invalid.
     fn link(&self) -> Option<String> {
         match (self.service(), self.resource_type()) {
-            // Alexa for Business
-            // ("a4b", "addressbook") => None,
-            // ("a4b", "conferenceprovider") => None,
-            // ("a4b", "contact") => None,
-            // ("a4b", "device") => None,
-            // ("a4b", "networkprofile") => None,
-            // ("a4b", "profile") => None,
-            // ("a4b", "room") => None,
-            // ("a4b", "schedule") => None,
-            // ("a4b", "skillgroup") => None,
-            // ("a4b", "user") => None,
-
             // IAM Access Analyzer
             ("access-analyzer", "analyzer") => Some(format!(
                 "https://{region}.{domain}/access-analyzer/home?region={region}#/analyzer/{resource}",
@@ -113,7 +101,12 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             )),
 
             // AWS Certificate Manager Private Certificate Authority
-            // ("acm-pca", "certificate-authority") => None,
+            ("acm-pca", "certificate-authority") => Some(format!(
+                "https://{region}.{domain}/acm-pca/home?region={region}#/details?arn={arn}",
+                region = self.region(),
+                domain = self.domain()?,
+                arn = self.encoded_arn(),
+            )),
 
             // Amazon Managed Workflows for Apache Airflow
             // ("airflow", "environment") => None,
@@ -155,8 +148,58 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("amplifybackend", "token") => None,
 
             // Amazon API Gateway
-            ("apigateway", "restapis") => Some(format!(
-                "https://{region}.{domain}/apigateway/main/apis/{resource}/resources?api={resource}&region={region}",
+            ("apigateway", "apikeys") => Some(format!(
+                "https://{region}.{domain}/apigateway/main/api-keys/{resource}?region={region}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            // HTTP and WebSocket APIs
+            ("apigateway", "apis") => {
+                let parts: Vec<&str> = self.resource_id().split('/').collect();
+                match parts.as_slice() {
+                    [api_id, "stages", stage_name] => Some(format!(
+                        "https://{region}.{domain}/apigateway/main/publish/stages?api={api_id}&stage={stage_name}&region={region}",
+                        region = self.region(),
+                        domain = self.domain()?,
+                        api_id = api_id,
+                        stage_name = stage_name,
+                    )),
+                    _ => Some(format!(
+                        "https://{region}.{domain}/apigateway/main/api-detail?api={api_id}&region={region}",
+                        region = self.region(),
+                        domain = self.domain()?,
+                        api_id = parts[0],
+                    )),
+                }
+            }
+            ("apigateway", "domainnames") => Some(format!(
+                "https://{region}.{domain}/apigateway/main/publish/domain-names?domain={resource}&region={region}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            // REST APIs
+            ("apigateway", "restapis") => {
+                let parts: Vec<&str> = self.resource_id().split('/').collect();
+                match parts.as_slice() {
+                    [api_id, "stages", stage_name] => Some(format!(
+                        "https://{region}.{domain}/apigateway/main/apis/{api_id}/stages?api={api_id}&stage={stage_name}&region={region}",
+                        region = self.region(),
+                        domain = self.domain()?,
+                        api_id = api_id,
+                        stage_name = stage_name,
+                    )),
+                    _ => Some(format!(
+                        "https://{region}.{domain}/apigateway/main/apis/{api_id}/resources?api={api_id}&region={region}",
+                        region = self.region(),
+                        domain = self.domain()?,
+                        api_id = parts[0],
+                    )),
+                }
+            }
+            ("apigateway", "usageplans") => Some(format!(
+                "https://{region}.{domain}/apigateway/main/usage-plans/{resource}?region={region}",
                 region = self.region(),
                 domain = self.domain()?,
                 resource = self.resource_id(),
@@ -196,18 +239,52 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("appmesh-preview", "virtualRouter") => None,
             // ("appmesh-preview", "virtualService") => None,
 
+            // AWS App Runner
+            ("apprunner", "service") => Some(format!(
+                "https://{region}.{domain}/apprunner/home?region={region}#/services/dashboard?service_arn={arn}",
+                region = self.region(),
+                domain = self.domain()?,
+                arn = self.encoded_arn(),
+            )),
+
             // Amazon AppStream 2.0
-            // ("appstream", "fleet") => None,
-            // ("appstream", "image") => None,
-            // ("appstream", "image-builder") => None,
-            // ("appstream", "stack") => None,
+            ("appstream", "fleet") => Some(format!(
+                "https://{region}.{domain}/appstream2/home?region={region}#/fleets/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("appstream", "image") => Some(format!(
+                "https://{region}.{domain}/appstream2/home?region={region}#/images/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("appstream", "image-builder") => Some(format!(
+                "https://{region}.{domain}/appstream2/home?region={region}#/image-builders/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("appstream", "stack") => Some(format!(
+                "https://{region}.{domain}/appstream2/home?region={region}#/stacks/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // AWS AppSync
+            // GraphQL APIs, and their sub-resources (`apis/<id>/types/...`).
+            ("appsync", "apis") => Some(format!(
+                "https://{region}.{domain}/appsync/home?region={region}#/{api_id}/v1/home",
+                region = self.region(),
+                domain = self.domain()?,
+                api_id = self.resource_id().split('/').next()?,
+            )),
             // ("appsync", "appsync") => None,
             // ("appsync", "datasource") => None,
             // ("appsync", "field") => None,
             // ("appsync", "function") => None,
-            // ("appsync", "graphqlapi") => None,
             // ("appsync", "type") => None,
 
             // Amazon Managed Service for Prometheus
@@ -219,14 +296,39 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("artifact", "report-package") => None,
 
             // Amazon Athena
-            // ("athena", "datacatalog") => None,
-            // ("athena", "workgroup") => None,
+            ("athena", "datacatalog") => Some(format!(
+                "https://{region}.{domain}/athena/home?region={region}#/data-sources/details/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("athena", "workgroup") => Some(format!(
+                "https://{region}.{domain}/athena/home?region={region}#/workgroups/details/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // AWS Audit Manager
-            // ("auditmanager", "assessment") => None,
+            ("auditmanager", "assessment") => Some(format!(
+                "https://{region}.{domain}/auditmanager/home?region={region}#/assessments/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("auditmanager", "assessmentControlSet") => None,
-            // ("auditmanager", "assessmentFramework") => None,
-            // ("auditmanager", "control") => None,
+            ("auditmanager", "assessmentFramework") => Some(format!(
+                "https://{region}.{domain}/auditmanager/home?region={region}#/frameworks/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("auditmanager", "control") => Some(format!(
+                "https://{region}.{domain}/auditmanager/home?region={region}#/controls/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // Amazon EC2 Auto Scaling
             ("autoscaling", "autoScalingGroup") => Some(format!(
@@ -245,19 +347,85 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("aws-marketplace", "Entity") => None,
 
             // AWS Backup
-            // ("backup", "backup-plan") => None,
+            ("backup", "backup-plan") => Some(format!(
+                "https://{domain}/backup/home?region={region}#/backupplan/details/{resource}",
+                domain = self.domain()?,
+                region = self.region(),
+                resource = self.resource_id(),
+            )),
             ("backup", "backup-vault") => Some(format!(
                 "https://{domain}/backup/home?region={region}#/backupvaults/details/{resource}",
                 domain = self.domain()?,
                 region = self.region(),
                 resource = self.resource_id(),
             )),
+            // Some recovery points are identified by the ARN of the underlying
+            // resource (an EBS or RDS snapshot, say), which we link directly.
+            ("backup", "recovery-point") if self.resource_id().starts_with("arn:") => {
+                crate::Arn::new(self.resource_id()).ok()?.link()
+            }
+            // Others are plain snapshot ids. The console identifies all other
+            // recovery points by their vault, which is not part of the ARN.
+            ("backup", "recovery-point") if self.resource_id().starts_with("snap-") => {
+                Some(format!(
+                    "https://{region}.{domain}/ec2/home?region={region}#SnapshotDetails:snapshotId={resource}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    resource = self.resource_id(),
+                ))
+            }
 
             // AWS Batch
-            // ("batch", "compute-environment") => None,
-            // ("batch", "job") => None,
-            // ("batch", "job-definition") => None,
-            // ("batch", "job-queue") => None,
+            ("batch", "compute-environment") => Some(format!(
+                "https://{region}.{domain}/batch/home?region={region}#compute-environments/detail/{arn}",
+                region = self.region(),
+                domain = self.domain()?,
+                arn = self.build(),
+            )),
+            ("batch", "job") => Some(format!(
+                "https://{region}.{domain}/batch/home?region={region}#jobs/detail/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("batch", "job-definition") => Some(format!(
+                "https://{region}.{domain}/batch/home?region={region}#job-definition/detail/{arn}",
+                region = self.region(),
+                domain = self.domain()?,
+                arn = self.build(),
+            )),
+            ("batch", "job-queue") => Some(format!(
+                "https://{region}.{domain}/batch/home?region={region}#queues/detail/{arn}",
+                region = self.region(),
+                domain = self.domain()?,
+                arn = self.build(),
+            )),
+
+            // Amazon Bedrock
+            ("bedrock", "agent") => Some(format!(
+                "https://{region}.{domain}/bedrock/home?region={region}#/agents/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("bedrock", "foundation-model") => Some(format!(
+                "https://{region}.{domain}/bedrock/home?region={region}#/providers?model={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("bedrock", "guardrail") => Some(format!(
+                "https://{region}.{domain}/bedrock/home?region={region}#/guardrails/guardrail/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("bedrock", "knowledge-base") => Some(format!(
+                "https://{region}.{domain}/bedrock/home?region={region}#/knowledge-bases/knowledge-base/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // Amazon Braket
             // ("braket", "quantum-task") => None,
@@ -283,6 +451,20 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("chime", "channel") => None,
             // ("chime", "meeting") => None,
 
+            // AWS Clean Rooms
+            ("cleanrooms", "collaboration") => Some(format!(
+                "https://{region}.{domain}/cleanrooms/home?region={region}#/collaborations/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("cleanrooms", "membership") => Some(format!(
+                "https://{region}.{domain}/cleanrooms/home?region={region}#/memberships/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+
             // AWS Cloud9
             // ("cloud9", "environment") => None,
 
@@ -300,7 +482,11 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("cloudformation", "type") => None,
 
             // Amazon CloudFront
-            // ("cloudfront", "cache-policy") => None,
+            ("cloudfront", "cache-policy") => Some(format!(
+                "https://{domain}/cloudfront/v4/home#/policies/cache/{resource}",
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             ("cloudfront", "distribution") => Some(format!(
                 "https://{domain}/cloudfront/v4/home#/distributions/{resource}",
                 domain = self.domain()?,
@@ -308,13 +494,43 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             )),
             // ("cloudfront", "field-level-encryption") => None,
             // ("cloudfront", "field-level-encryption-profile") => None,
+            ("cloudfront", "function") => Some(format!(
+                "https://{domain}/cloudfront/v4/home#/functions/{resource}",
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("cloudfront", "origin-access-control") => Some(format!(
+                "https://{domain}/cloudfront/v4/home#/originAccess/{resource}",
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("cloudfront", "origin-access-identity") => None,
-            // ("cloudfront", "origin-request-policy") => None,
+            ("cloudfront", "origin-request-policy") => Some(format!(
+                "https://{domain}/cloudfront/v4/home#/policies/origin/{resource}",
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("cloudfront", "response-headers-policy") => Some(format!(
+                "https://{domain}/cloudfront/v4/home#/policies/responseHeaders/{resource}",
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            // RTMP distributions were discontinued and no longer show up in the console.
             // ("cloudfront", "streaming-distribution") => None,
 
             // AWS CloudHSM
-            // ("cloudhsm", "backup") => None,
-            // ("cloudhsm", "cluster") => None,
+            ("cloudhsm", "backup") => Some(format!(
+                "https://{region}.{domain}/cloudhsm/home?region={region}#/backups/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("cloudhsm", "cluster") => Some(format!(
+                "https://{region}.{domain}/cloudhsm/home?region={region}#/clusters/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // Amazon CloudSearch
             // ("cloudsearch", "domain") => None,
@@ -323,7 +539,24 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("cloudshell", "Environment") => None,
 
             // AWS CloudTrail
-            // ("cloudtrail", "trail") => None,
+            ("cloudtrail", "channel") => Some(format!(
+                "https://{region}.{domain}/cloudtrailv2/home?region={region}#/lake/integrations/{arn}",
+                region = self.region(),
+                domain = self.domain()?,
+                arn = self.build(),
+            )),
+            ("cloudtrail", "eventdatastore") => Some(format!(
+                "https://{region}.{domain}/cloudtrailv2/home?region={region}#/lake/eventDataStores/{arn}",
+                region = self.region(),
+                domain = self.domain()?,
+                arn = self.build(),
+            )),
+            ("cloudtrail", "trail") => Some(format!(
+                "https://{region}.{domain}/cloudtrailv2/home?region={region}#/trails/{arn}",
+                region = self.region(),
+                domain = self.domain()?,
+                arn = self.build(),
+            )),
 
             // Amazon CloudWatch
             // ("cloudwatch", "alarm") => None,
@@ -331,19 +564,88 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("cloudwatch", "insight-rule") => None,
 
             // AWS CodeArtifact
-            // ("codeartifact", "domain") => None,
-            // ("codeartifact", "package") => None,
-            // ("codeartifact", "repository") => None,
+            ("codeartifact", "domain") => Some(format!(
+                "https://{region}.{domain}/codesuite/codeartifact/d/{account}/{resource}?region={region}",
+                region = self.region(),
+                domain = self.domain()?,
+                account = self.account(),
+                resource = self.resource_id(),
+            )),
+            ("codeartifact", "package") => {
+                let mut parts = self.resource_id().split('/');
+                let domain_name = parts.next()?;
+                let repository_name = parts.next()?;
+                let format = parts.next()?;
+                let namespace = parts.next()?;
+                let package_name = parts.next()?;
+                let package = if namespace.is_empty() {
+                    package_name.to_string()
+                } else {
+                    format!("{namespace}/{package_name}")
+                };
+                Some(format!(
+                    "https://{region}.{domain}/codesuite/codeartifact/d/{account}/{domain_name}/r/{repository_name}/p/{format}/{package}/versions?region={region}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    account = self.account(),
+                    domain_name = domain_name,
+                    repository_name = repository_name,
+                    format = format,
+                    package = package,
+                ))
+            }
+            ("codeartifact", "repository") => {
+                let (domain_name, repository_name) = self.resource_id().split_once('/')?;
+                Some(format!(
+                    "https://{region}.{domain}/codesuite/codeartifact/d/{account}/{domain_name}/r/{repository_name}?region={region}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    account = self.account(),
+                    domain_name = domain_name,
+                    repository_name = repository_name,
+                ))
+            }
 
             // AWS CodeBuild
-            // ("codebuild", "build") => None,
-            // ("codebuild", "build-batch") => None,
-            // ("codebuild", "project") => None,
+            ("codebuild", "build") if !self.resource_revision().is_empty() => Some(format!(
+                "https://{region}.{domain}/codesuite/codebuild/{account}/projects/{project}/build/{project}%3A{id}?region={region}",
+                region = self.region(),
+                domain = self.domain()?,
+                account = self.account(),
+                project = self.resource_id(),
+                id = self.resource_revision(),
+            )),
+            ("codebuild", "build-batch") if !self.resource_revision().is_empty() => Some(format!(
+                "https://{region}.{domain}/codesuite/codebuild/{account}/projects/{project}/batch/{project}%3A{id}?region={region}",
+                region = self.region(),
+                domain = self.domain()?,
+                account = self.account(),
+                project = self.resource_id(),
+                id = self.resource_revision(),
+            )),
+            ("codebuild", "project") => Some(format!(
+                "https://{region}.{domain}/codesuite/codebuild/{account}/projects/{resource}?region={region}",
+                region = self.region(),
+                domain = self.domain()?,
+                account = self.account(),
+                resource = self.resource_id(),
+            )),
             // ("codebuild", "report") => None,
-            // ("codebuild", "report-group") => None,
+            ("codebuild", "report-group") => Some(format!(
+                "https://{region}.{domain}/codesuite/codebuild/{account}/testReports/reportGroups/{resource}?region={region}",
+                region = self.region(),
+                domain = self.domain()?,
+                account = self.account(),
+                resource = self.resource_id(),
+            )),
 
-            // Amazon CodeGuru Reviewer
-            // ("codecommit", "repository") => None,
+            // AWS CodeCommit
+            ("codecommit", "") => Some(format!(
+                "https://{region}.{domain}/codesuite/codecommit/repositories/{resource}/browse?region={region}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // AWS CodeConnections
             ("codeconnections", "connection") => Some(format!(
@@ -357,9 +659,24 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             )),
 
             // AWS CodeDeploy
-            // ("codedeploy", "application") => None,
+            ("codedeploy", "application") => Some(format!(
+                "https://{region}.{domain}/codesuite/codedeploy/applications/{resource}?region={region}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("codedeploy", "deploymentconfig") => None,
-            // ("codedeploy", "deploymentgroup") => None,
+            ("codedeploy", "deploymentgroup") => {
+                let (application_name, deployment_group_name) =
+                    self.resource_id().split_once('/')?;
+                Some(format!(
+                    "https://{region}.{domain}/codesuite/codedeploy/applications/{application_name}/deployment-groups/{deployment_group_name}?region={region}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    application_name = application_name,
+                    deployment_group_name = deployment_group_name,
+                ))
+            }
             // ("codedeploy", "instance") => None,
 
             // Amazon CodeGuru Profiler
@@ -382,9 +699,6 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("codepipeline", "stage") => None,
             // ("codepipeline", "webhook") => None,
 
-            // AWS CodeStar
-            // ("codestar", "project") => None,
-
             // AWS CodeStar Connections
             ("codestar-connections", "connection") => Some(format!(
                 "https://{region}.{domain}/codesuite/settings/{account}/{region}/{service}/{resource_type}s/{resource}",
@@ -401,10 +715,25 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("codestar-notifications", "notificationrule") => None,
 
             // Amazon Cognito Identity
-            // ("cognito-identity", "identitypool") => None,
+            ("cognito-identity", "identitypool") if !self.resource_revision().is_empty() => {
+                // Identity pool ids look like `us-east-1:<uuid>`, so the
+                // parser splits the uuid off as the resource revision.
+                Some(format!(
+                    "https://{region}.{domain}/cognito/v2/identity/identity-pools/{resource}:{resource_revision}/overview?region={region}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    resource = self.resource_id(),
+                    resource_revision = self.resource_revision(),
+                ))
+            }
 
             // Amazon Cognito User Pools
-            // ("cognito-idp", "userpool") => None,
+            ("cognito-idp", "userpool") => Some(format!(
+                "https://{region}.{domain}/cognito/v2/idp/user-pools/{resource}/overview?region={region}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // Amazon Cognito Sync
             // ("cognito-sync", "dataset") => None,
@@ -418,27 +747,83 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("comprehend", "entity-recognizer-endpoint") => None,
 
             // AWS Config
-            // ("config", "AggregationAuthorization") => None,
-            // ("config", "ConfigRule") => None,
-            // ("config", "ConfigurationAggregator") => None,
-            // ("config", "ConformancePack") => None,
-            // ("config", "OrganizationConfigRule") => None,
-            // ("config", "OrganizationConformancePack") => None,
-            // ("config", "RemediationConfiguration") => None,
-            // ("config", "StoredQuery") => None,
+            // ("config", "aggregation-authorization") => None,
+            // Rule and aggregator ARNs only carry the generated id, the console wants the name.
+            // ("config", "config-aggregator") => None,
+            // ("config", "config-rule") => None,
+            ("config", "conformance-pack") => Some(format!(
+                "https://{region}.{domain}/config/home?region={region}#/conformance-packs/details?conformancePackName={pack_name}",
+                region = self.region(),
+                domain = self.domain()?,
+                pack_name = self.resource_id().split('/').next()?,
+            )),
+            // ("config", "organization-config-rule") => None,
+            // ("config", "organization-conformance-pack") => None,
+            ("config", "remediation-configuration") => Some(format!(
+                "https://{region}.{domain}/config/home?region={region}#/rules/details?configRuleName={rule_name}",
+                region = self.region(),
+                domain = self.domain()?,
+                rule_name = self.resource_id().split('/').next()?,
+            )),
+            ("config", "stored-query") => Some(format!(
+                "https://{region}.{domain}/config/home?region={region}#/queries/edit/{query_name}",
+                region = self.region(),
+                domain = self.domain()?,
+                query_name = self.resource_id().split('/').next()?,
+            )),
 
             // Amazon Connect
-            // ("connect", "contact") => None,
-            // ("connect", "contact-flow") => None,
+            // Queues, flows, users and the like are nested under the instance (`<id>/queue/<id>`).
+            // They are managed on the instance's own website, whose alias is not part of the ARN,
+            // so we go through the console's federation endpoint, which knows the way.
+            ("connect", "instance") => {
+                let parts: Vec<&str> = self.resource_id().split('/').collect();
+                let (instance_id, destination) = match parts.as_slice() {
+                    [instance_id] => {
+                        return Some(format!(
+                            "https://{region}.{domain}/connect/v2/app/instances/{instance_id}?region={region}",
+                            region = self.region(),
+                            domain = self.domain()?,
+                            instance_id = instance_id,
+                        ));
+                    }
+                    [instance_id, "agent", user_id] => {
+                        (instance_id, format!("/users/edit?id={user_id}"))
+                    }
+                    [instance_id, "contact", contact_id] => (
+                        instance_id,
+                        format!("/contact-trace-records/details/{contact_id}"),
+                    ),
+                    [instance_id, "contact-flow", flow_id] => {
+                        (instance_id, format!("/contact-flows/edit?id={flow_id}"))
+                    }
+                    [instance_id, "operating-hours", hours_id] => {
+                        (instance_id, format!("/operating-hours/edit?id={hours_id}"))
+                    }
+                    [instance_id, "queue", queue_id] => {
+                        (instance_id, format!("/queues/edit?id={queue_id}"))
+                    }
+                    [instance_id, "routing-profile", profile_id] => (
+                        instance_id,
+                        format!("/routing-profiles/edit?id={profile_id}"),
+                    ),
+                    [instance_id, "transfer-destination", quick_connect_id] => (
+                        instance_id,
+                        format!("/quick-connects/edit?id={quick_connect_id}"),
+                    ),
+                    _ => return None,
+                };
+                Some(format!(
+                    "https://{region}.{domain}/connect/federate/{instance_id}?destination={destination}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    instance_id = instance_id,
+                    destination = percent_encode(&destination),
+                ))
+            }
             // ("connect", "hierarchy-group") => None,
-            // ("connect", "hours-of-operation") => None,
-            // ("connect", "instance") => None,
             // ("connect", "phone-number") => None,
-            // ("connect", "queue") => None,
-            // ("connect", "quick-connect") => None,
-            // ("connect", "routing-profile") => None,
             // ("connect", "security-profile") => None,
-            // ("connect", "user") => None,
 
             // AWS Cost and Usage Report
             // ("cur", "cur") => None,
@@ -457,24 +842,45 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("dataexchange", "revisions") => None,
 
             // DataSync
-            // ("datasync", "agent") => None,
-            // ("datasync", "location") => None,
-            // ("datasync", "task") => None,
-            // ("datasync", "taskexecution") => None,
+            ("datasync", "agent") => Some(format!(
+                "https://{region}.{domain}/datasync/home?region={region}#/agents/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("datasync", "location") => Some(format!(
+                "https://{region}.{domain}/datasync/home?region={region}#/locations/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("datasync", "task") => match self.resource_id().split_once("/execution/") {
+                Some((task_id, execution_id)) => Some(format!(
+                    "https://{region}.{domain}/datasync/home?region={region}#/history/{task_id}/{execution_id}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    task_id = task_id,
+                    execution_id = execution_id,
+                )),
+                None => Some(format!(
+                    "https://{region}.{domain}/datasync/home?region={region}#/tasks/{resource}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    resource = self.resource_id(),
+                )),
+            },
+
+            // Amazon DataZone
+            ("datazone", "domain") => Some(format!(
+                "https://{region}.{domain}/datazone/home?region={region}#/domains/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // Amazon DynamoDB Accelerator (DAX)
             // ("dax", "application") => None,
 
-            // AWS DeepComposer
-            // ("deepcomposer", "audio") => None,
-            // ("deepcomposer", "composition") => None,
-            // ("deepcomposer", "model") => None,
-
-            // AWS DeepLens
-            // ("deeplens", "device") => None,
-            // ("deeplens", "model") => None,
-            // ("deeplens", "project") => None,
-
             // AWS DeepRacer
             // ("deepracer", "evaluation_job") => None,
             // ("deepracer", "leaderboard") => None,
@@ -484,7 +890,7 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("deepracer", "training_job") => None,
 
             // Amazon Detective
-            // ("detective", "Graph") => None,
+            // ("detective", "graph") => None,
 
             // AWS Device Farm
             // ("devicefarm", "artifact") => None,
@@ -515,22 +921,46 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("dlm", "policy") => None,
 
             // AWS Database Migration Service
-            // ("dms", "Certificate") => None,
-            // ("dms", "Endpoint") => None,
-            // ("dms", "EventSubscription") => None,
-            // ("dms", "ReplicationInstance") => None,
-            // ("dms", "ReplicationSubnetGroup") => None,
-            // ("dms", "ReplicationTask") => None,
-            // ("dms", "ReplicationTaskAssessmentRun") => None,
-            // ("dms", "ReplicationTaskIndividualAssessment") => None,
-
-            // AWS Directory Service
-            // ("ds", "directory") => None,
-
-            // Amazon DynamoDB
-            // ("dynamodb", "backup") => None,
-            // ("dynamodb", "export") => None,
-            // ("dynamodb", "global-table") => None,
+            // The ids in these ARNs are generated, so the console is given the whole ARN.
+            // ("dms", "assessment-run") => None,
+            // ("dms", "cert") => None,
+            ("dms", "endpoint") => Some(format!(
+                "https://{region}.{domain}/dms/v2/home?region={region}#endpointDetails?arn={arn}",
+                region = self.region(),
+                domain = self.domain()?,
+                arn = self.encoded_arn(),
+            )),
+            // ("dms", "es") => None,
+            // ("dms", "individual-assessment") => None,
+            ("dms", "rep") => Some(format!(
+                "https://{region}.{domain}/dms/v2/home?region={region}#replicationInstanceDetails?arn={arn}",
+                region = self.region(),
+                domain = self.domain()?,
+                arn = self.encoded_arn(),
+            )),
+            // ("dms", "subgrp") => None,
+            ("dms", "task") => Some(format!(
+                "https://{region}.{domain}/dms/v2/home?region={region}#taskDetails?arn={arn}",
+                region = self.region(),
+                domain = self.domain()?,
+                arn = self.encoded_arn(),
+            )),
+
+            // Amazon DocumentDB Elastic Clusters
+            ("docdb-elastic", "cluster") => Some(format!(
+                "https://{region}.{domain}/docdb/home?region={region}#elastic-cluster-details/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+
+            // AWS Directory Service
+            // ("ds", "directory") => None,
+
+            // Amazon DynamoDB
+            // ("dynamodb", "backup") => None,
+            // ("dynamodb", "export") => None,
+            // ("dynamodb", "global-table") => None,
             // ("dynamodb", "index") => None,
             // ("dynamodb", "stream") => None,
             ("dynamodb", "table") => Some(format!(
@@ -541,18 +971,63 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             )),
 
             // AWS Elastic Compute Cloud
-            // ("ec2", "capacity-reservation") => None,
-            // ("ec2", "carrier-gateway") => None,
-            // ("ec2", "client-vpn-endpoint") => None,
-            // ("ec2", "customer-gateway") => None,
-            // ("ec2", "dedicated-host") => None,
-            // ("ec2", "dhcp-options") => None,
-            // ("ec2", "egress-only-internet-gateway") => None,
+            ("ec2", "capacity-reservation") => Some(format!(
+                "https://{region}.{domain}/ec2/home?region={region}#CapacityReservationDetails:crId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("ec2", "carrier-gateway") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#CarrierGatewayDetails:carrierGatewayId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("ec2", "client-vpn-endpoint") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#ClientVPNEndpointDetails:clientVpnEndpointId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("ec2", "customer-gateway") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#CustomerGatewayDetails:customerGatewayId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("ec2", "dedicated-host") => Some(format!(
+                "https://{region}.{domain}/ec2/home?region={region}#HostDetails:hostId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("ec2", "dhcp-options") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#DhcpOptionsDetails:DhcpOptionsId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("ec2", "egress-only-internet-gateway") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#EgressOnlyInternetGatewayDetails:egressOnlyInternetGatewayId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("ec2", "elastic-gpu") => None,
-            // ("ec2", "elastic-ip") => None,
+            ("ec2", "elastic-ip") => Some(format!(
+                "https://{region}.{domain}/ec2/home?region={region}#ElasticIpDetails:AllocationId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("ec2", "export-image-task") => None,
             // ("ec2", "export-instance-task") => None,
-            // ("ec2", "fleet") => None,
+            ("ec2", "fleet") => Some(format!(
+                "https://{region}.{domain}/ec2/home?region={region}#FleetDetails:fleetId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("ec2", "fpga-image") => None,
             // ("ec2", "host-reservation") => None,
             ("ec2", "image") => Some(format!(
@@ -569,10 +1044,20 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
                 domain = self.domain()?,
                 resource = self.resource_id(),
             )),
-            // ("ec2", "internet-gateway") => None,
+            ("ec2", "internet-gateway") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#InternetGatewayDetails:internetGatewayId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("ec2", "ipv4pool-ec2") => None,
             // ("ec2", "ipv6pool-ec2") => None,
-            // ("ec2", "key-pair") => None,
+            ("ec2", "key-pair") => Some(format!(
+                "https://{region}.{domain}/ec2/home?region={region}#KeyPairs:search={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             ("ec2", "launch-template") => Some(format!(
                 "https://{region}.{domain}/ec2/home?region={region}#LaunchTemplateDetails:launchTemplateId={resource}",
                 region = self.region(),
@@ -591,14 +1076,44 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
                 region = self.region(),
                 resource = self.resource_id(),
             )),
-            // ("ec2", "network-acl") => None,
+            ("ec2", "network-acl") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#NetworkAclDetails:networkAclId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("ec2", "network-insights-analysis") => None,
             // ("ec2", "network-insights-path") => None,
-            // ("ec2", "network-interface") => None,
-            // ("ec2", "placement-group") => None,
-            // ("ec2", "prefix-list") => None,
-            // ("ec2", "reserved-instances") => None,
-            // ("ec2", "route-table") => None,
+            ("ec2", "network-interface") => Some(format!(
+                "https://{region}.{domain}/ec2/home?region={region}#NetworkInterface:networkInterfaceId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("ec2", "placement-group") => Some(format!(
+                "https://{region}.{domain}/ec2/home?region={region}#PlacementGroups:search={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("ec2", "prefix-list") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#PrefixListDetails:prefixListId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("ec2", "reserved-instances") => Some(format!(
+                "https://{region}.{domain}/ec2/home?region={region}#ReservedInstances:reservedInstancesId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("ec2", "route-table") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#RouteTableDetails:RouteTableId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             ("ec2", "security-group") => Some(format!(
                 "https://{region}.{domain}/vpc/home?region={region}#SecurityGroup:groupId={resource}",
                 domain = self.domain()?,
@@ -611,8 +1126,18 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
                 domain = self.domain()?,
                 resource = self.resource_id(),
             )),
-            // ("ec2", "spot-fleet-request") => None,
-            // ("ec2", "spot-instances-request") => None,
+            ("ec2", "spot-fleet-request") => Some(format!(
+                "https://{region}.{domain}/ec2/home?region={region}#SpotFleetDetails:id={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("ec2", "spot-instances-request") => Some(format!(
+                "https://{region}.{domain}/ec2/home?region={region}#SpotInstancesDetails:id={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             ("ec2", "subnet") => Some(format!(
                 "https://{region}.{domain}/vpc/home?region={region}#SubnetDetails:subnetId={resource}",
                 domain = self.domain()?,
@@ -623,11 +1148,49 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("ec2", "traffic-mirror-filter-rule") => None,
             // ("ec2", "traffic-mirror-session") => None,
             // ("ec2", "traffic-mirror-target") => None,
-            // ("ec2", "transit-gateway") => None,
-            // ("ec2", "transit-gateway-attachment") => None,
+            ("ec2", "transit-gateway") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#TransitGatewayDetails:transitGatewayId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("ec2", "transit-gateway-attachment") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#TransitGatewayAttachmentDetails:transitGatewayAttachmentId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("ec2", "transit-gateway-connect-peer") => None,
-            // ("ec2", "transit-gateway-multicast-domain") => None,
-            // ("ec2", "transit-gateway-route-table") => None,
+            ("ec2", "transit-gateway-multicast-domain") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#TransitGatewayMulticastDomainDetails:transitGatewayMulticastDomainId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("ec2", "transit-gateway-route-table") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#TransitGatewayRouteTableDetails:transitGatewayRouteTableId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("ec2", "verified-access-endpoint") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#VerifiedAccessEndpointDetails:verifiedAccessEndpointId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("ec2", "verified-access-group") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#VerifiedAccessGroupDetails:verifiedAccessGroupId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("ec2", "verified-access-instance") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#VerifiedAccessInstanceDetails:verifiedAccessInstanceId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             ("ec2", "volume") => Some(format!(
                 "https://{region}.{domain}/ec2/home?region={region}#VolumeDetails:volumeId={resource}",
                 region = self.region(),
@@ -646,18 +1209,72 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
                 region = self.region(),
                 resource = self.resource_id(),
             )),
-            // ("ec2", "vpc-endpoint-service") => None,
+            ("ec2", "vpc-endpoint-service") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#EndpointServiceDetails:endpointServiceId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("ec2", "vpc-flow-log") => None,
-            // ("ec2", "vpc-peering-connection") => None,
-            // ("ec2", "vpn-connection") => None,
-            // ("ec2", "vpn-gateway") => None,
+            ("ec2", "vpc-peering-connection") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#PeeringConnectionDetails:VpcPeeringConnectionId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("ec2", "vpn-connection") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#VpnConnectionDetails:VpnConnectionId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("ec2", "vpn-gateway") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#VpnGatewayDetails:VpnGatewayId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // Amazon Elastic Container Registry
-            // ("ecr", "repository") => None,
+            ("ecr", "repository") => {
+                // Image references are either `name@sha256:digest` or `name:tag`.
+                // The parser may have split them off as the resource revision.
+                let reference = match self.resource_revision() {
+                    "" => self.resource_id().to_string(),
+                    revision => format!("{}:{}", self.resource_id(), revision),
+                };
+                if let Some((name, digest)) = reference.split_once('@') {
+                    Some(format!(
+                        "https://{region}.{domain}/ecr/repositories/private/{account}/{name}/_/image/{digest}/details?region={region}",
+                        region = self.region(),
+                        domain = self.domain()?,
+                        account = self.account(),
+                        name = name,
+                        digest = digest,
+                    ))
+                } else {
+                    let name = reference
+                        .rsplit_once(':')
+                        .map_or(reference.as_str(), |(name, _tag)| name);
+                    Some(format!(
+                        "https://{region}.{domain}/ecr/repositories/private/{account}/{name}?region={region}",
+                        region = self.region(),
+                        domain = self.domain()?,
+                        account = self.account(),
+                        name = name,
+                    ))
+                }
+            }
 
             // Amazon Elastic Container Registry Public
             // ("ecr-public", "registry") => None,
-            // ("ecr-public", "repository") => None,
+            // Public repositories only exist in us-east-1, and their ARNs have no region.
+            ("ecr-public", "repository") => Some(format!(
+                "https://{domain}/ecr/repositories/public/{account}/{resource}?region=us-east-1",
+                domain = self.domain()?,
+                account = self.account(),
+                resource = self.resource_id(),
+            )),
 
             // Amazon Elastic Container Service
             // ("ecs", "capacity-provider") => None,
@@ -700,14 +1317,43 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("ecs", "task-set") => None,
 
             // Amazon Elastic Container Service for Kubernetes
-            // ("eks", "addon") => None,
+            ("eks", "addon") => {
+                let mut parts = self.resource_id().split('/');
+                Some(format!(
+                    "https://{domain}/eks/home?region={region}#/clusters/{cluster_name}/add-ons/{addon_name}",
+                    domain = self.domain()?,
+                    region = self.region(),
+                    cluster_name = parts.next()?,
+                    addon_name = parts.next()?,
+                ))
+            }
             ("eks", "cluster") => Some(format!(
                 "https://{domain}/eks/home?region={region}#/clusters/{resource}",
                 domain = self.domain()?,
                 region = self.region(),
                 resource = self.resource_id(),
             )),
-            // ("eks", "fargateprofile") => None,
+            ("eks", "fargateprofile") => {
+                let mut parts = self.resource_id().split('/');
+                Some(format!(
+                    "https://{domain}/eks/home?region={region}#/clusters/{cluster_name}/fargate-profiles/{fargate_profile_name}",
+                    domain = self.domain()?,
+                    region = self.region(),
+                    cluster_name = parts.next()?,
+                    fargate_profile_name = parts.next()?,
+                ))
+            }
+            ("eks", "identityproviderconfig") => {
+                let mut parts = self.resource_id().split('/');
+                Some(format!(
+                    "https://{domain}/eks/home?region={region}#/clusters/{cluster_name}/identity-provider-configs/{config_type}/{config_name}",
+                    domain = self.domain()?,
+                    region = self.region(),
+                    cluster_name = parts.next()?,
+                    config_type = parts.next()?,
+                    config_name = parts.next()?,
+                ))
+            }
             ("eks", "nodegroup") => {
                 let mut parts = self.resource_id().split('/');
                 Some(format!(
@@ -718,34 +1364,112 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
                     nodegroup_name = parts.next()?,
                 ))
             }
-
-            // Amazon Elastic Inference
-            // ("elastic-inference", "accelerator") => None,
-            // ("elastic-inference", "elastic-inference") => None,
+            ("eks", "podidentityassociation") => {
+                let mut parts = self.resource_id().split('/');
+                Some(format!(
+                    "https://{domain}/eks/home?region={region}#/clusters/{cluster_name}/pod-identity-associations/{association_id}",
+                    domain = self.domain()?,
+                    region = self.region(),
+                    cluster_name = parts.next()?,
+                    association_id = parts.next()?,
+                ))
+            }
 
             // Amazon ElastiCache
-            // ("elasticache", "cluster") => None,
-            // ("elasticache", "globalreplicationgroup") => None,
-            // ("elasticache", "parametergroup") => None,
-            // ("elasticache", "replicationgroup") => None,
+            ("elasticache", "cluster") => Some(format!(
+                "https://{region}.{domain}/elasticache/home?region={region}#/redis/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("elasticache", "globalreplicationgroup") => Some(format!(
+                "https://{region}.{domain}/elasticache/home?region={region}#/global-datastore/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("elasticache", "parametergroup") => Some(format!(
+                "https://{region}.{domain}/elasticache/home?region={region}#/parameter-groups/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("elasticache", "replicationgroup") => Some(format!(
+                "https://{region}.{domain}/elasticache/home?region={region}#/redis/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("elasticache", "reserved-instance") => None,
             // ("elasticache", "securitygroup") => None,
-            // ("elasticache", "snapshot") => None,
-            // ("elasticache", "subnetgroup") => None,
-            // ("elasticache", "user") => None,
-            // ("elasticache", "usergroup") => None,
+            ("elasticache", "snapshot") => Some(format!(
+                "https://{region}.{domain}/elasticache/home?region={region}#/snapshots/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("elasticache", "subnetgroup") => Some(format!(
+                "https://{region}.{domain}/elasticache/home?region={region}#/subnet-groups/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("elasticache", "user") => Some(format!(
+                "https://{region}.{domain}/elasticache/home?region={region}#/users/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("elasticache", "usergroup") => Some(format!(
+                "https://{region}.{domain}/elasticache/home?region={region}#/user-groups/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // AWS Elastic Beanstalk
-            // ("elasticbeanstalk", "application") => None,
-            // ("elasticbeanstalk", "applicationversion") => None,
-            // ("elasticbeanstalk", "configurationtemplate") => None,
-            // ("elasticbeanstalk", "environment") => None,
+            ("elasticbeanstalk", "application") => Some(format!(
+                "https://{region}.{domain}/elasticbeanstalk/home?region={region}#/application/overview?applicationName={application_name}",
+                region = self.region(),
+                domain = self.domain()?,
+                application_name = self.resource_id().split('/').next()?,
+            )),
+            ("elasticbeanstalk", "applicationversion") => Some(format!(
+                "https://{region}.{domain}/elasticbeanstalk/home?region={region}#/application/versions?applicationName={application_name}",
+                region = self.region(),
+                domain = self.domain()?,
+                application_name = self.resource_id().split('/').next()?,
+            )),
+            ("elasticbeanstalk", "configurationtemplate") => Some(format!(
+                "https://{region}.{domain}/elasticbeanstalk/home?region={region}#/application/savedconfigurations?applicationName={application_name}",
+                region = self.region(),
+                domain = self.domain()?,
+                application_name = self.resource_id().split('/').next()?,
+            )),
+            // The console addresses environments by id, which the ARN lacks, so this opens the
+            // application overview that lists them.
+            ("elasticbeanstalk", "environment") => Some(format!(
+                "https://{region}.{domain}/elasticbeanstalk/home?region={region}#/application/overview?applicationName={application_name}",
+                region = self.region(),
+                domain = self.domain()?,
+                application_name = self.resource_id().split('/').next()?,
+            )),
             // ("elasticbeanstalk", "platform") => None,
             // ("elasticbeanstalk", "solutionstack") => None,
 
             // Amazon Elastic File System
-            // ("elasticfilesystem", "access-point") => None,
-            // ("elasticfilesystem", "file-system") => None,
+            ("elasticfilesystem", "access-point") => Some(format!(
+                "https://{region}.{domain}/efs/home?region={region}#/access-points/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("elasticfilesystem", "file-system") => Some(format!(
+                "https://{region}.{domain}/efs/home?region={region}#/file-systems/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // Elastic Load Balancing
             // ("elasticloadbalancing", "listener-rule/app") => None,
@@ -766,11 +1490,6 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("elasticmapreduce", "cluster") => None,
             // ("elasticmapreduce", "editor") => None,
 
-            // Amazon Elastic Transcoder
-            // ("elastictranscoder", "job") => None,
-            // ("elastictranscoder", "pipeline") => None,
-            // ("elastictranscoder", "preset") => None,
-
             // AWS Elemental Appliances and Software Activation Service
             // ("elemental-activations", "activation") => None,
 
@@ -781,18 +1500,76 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("emr-containers", "jobRun") => None,
             // ("emr-containers", "virtualCluster") => None,
 
+            // AWS Entity Resolution
+            ("entityresolution", "matchingworkflow") => Some(format!(
+                "https://{region}.{domain}/entityresolution/home?region={region}#/matchingworkflows/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("entityresolution", "schemamapping") => Some(format!(
+                "https://{region}.{domain}/entityresolution/home?region={region}#/schemamappings/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+
             // Amazon Elasticsearch Service
             // ("es", "domain") => None,
 
             // Amazon EventBridge
-            // ("events", "archive") => None,
-            // ("events", "event-bus") => None,
+            ("events", "archive") => Some(format!(
+                "https://{region}.{domain}/events/home?region={region}#/archives/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("events", "event-bus") => Some(format!(
+                "https://{region}.{domain}/events/home?region={region}#/eventbus/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("events", "event-source") => None,
-            // ("events", "replay") => None,
-            // ("events", "rule") => None,
+            ("events", "replay") => Some(format!(
+                "https://{region}.{domain}/events/home?region={region}#/replays/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("events", "rule") => {
+                // Rules on the default event bus don't include the bus name.
+                // Partner event bus names contain slashes, rule names don't.
+                let (event_bus_name, rule_name) = self
+                    .resource_id()
+                    .rsplit_once('/')
+                    .unwrap_or(("default", self.resource_id()));
+                Some(format!(
+                    "https://{region}.{domain}/events/home?region={region}#/eventbus/{event_bus_name}/rules/{rule_name}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    event_bus_name = percent_encode(event_bus_name),
+                    rule_name = rule_name,
+                ))
+            }
 
             // Amazon API Gateway
             // ("execute-api", "execute-api-general") => None,
+            // These ARNs look like `<api-id>/<stage>/<method>/<path>`, so the
+            // parser treats the API id as resource type. They don't say whether
+            // the API is a REST, HTTP or WebSocket API, so we link the API itself.
+            // Policies may use wildcards instead of an API id or stage.
+            ("execute-api", api_id) if self.has_path() && !api_id.contains('*') => {
+                match self.resource_id().split('/').next()? {
+                    "" | "*" => None,
+                    _ => Some(format!(
+                        "https://{region}.{domain}/apigateway/main/apis/{api_id}?api={api_id}&region={region}",
+                        region = self.region(),
+                        domain = self.domain()?,
+                        api_id = api_id,
+                    )),
+                }
+            }
 
             // Amazon Kinesis Firehose
             ("firehose", "deliverystream") => Some(format!(
@@ -834,8 +1611,18 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("freertos", "configuration") => None,
 
             // Amazon FSx
-            // ("fsx", "backup") => None,
-            // ("fsx", "file-system") => None,
+            ("fsx", "backup") => Some(format!(
+                "https://{region}.{domain}/fsx/home?region={region}#backup-details/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("fsx", "file-system") => Some(format!(
+                "https://{region}.{domain}/fsx/home?region={region}#file-system-details/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("fsx", "task") => None,
 
             // Amazon GameLift
@@ -855,28 +1642,96 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("geo", "tracker") => None,
 
             // Amazon Glacier
-            // ("glacier", "vault") => None,
+            ("glacier", "vaults") => Some(format!(
+                "https://{region}.{domain}/glacier/home?region={region}#/vault/{resource}/view/properties",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // AWS Global Accelerator
-            // ("globalaccelerator", "accelerator") => None,
-            // ("globalaccelerator", "endpointgroup") => None,
-            // ("globalaccelerator", "listener") => None,
+            // Listener and endpoint group ARNs extend the accelerator ARN, so they open its page.
+            ("globalaccelerator", "accelerator") => {
+                let arn = self.build();
+                let (accelerator_arn, _) = arn.split_once("/listener/").unwrap_or((&arn, ""));
+                Some(format!(
+                    // Global Accelerator's control plane only lives in us-west-2.
+                    "https://us-west-2.{domain}/globalaccelerator/home?region=us-west-2#AcceleratorDetails:AcceleratorArn={accelerator_arn}",
+                    domain = self.domain()?,
+                    accelerator_arn = accelerator_arn,
+                ))
+            }
 
             // AWS Glue
             // ("glue", "catalog") => None,
-            // ("glue", "connection") => None,
-            // ("glue", "crawler") => None,
-            // ("glue", "database") => None,
+            ("glue", "connection") => Some(format!(
+                "https://{region}.{domain}/glue/home?region={region}#/v2/data-catalog/connections/view/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("glue", "crawler") => Some(format!(
+                "https://{region}.{domain}/glue/home?region={region}#/v2/data-catalog/crawlers/view/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("glue", "database") => Some(format!(
+                "https://{region}.{domain}/glue/home?region={region}#/v2/data-catalog/databases/view/{resource}?catalogId={account}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+                account = self.account(),
+            )),
             // ("glue", "devendpoint") => None,
-            // ("glue", "job") => None,
+            ("glue", "job") => Some(format!(
+                "https://{region}.{domain}/gluestudio/home?region={region}#/editor/job/{resource}/details",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("glue", "mlTransform") => None,
-            // ("glue", "registry") => None,
-            // ("glue", "schema") => None,
-            // ("glue", "table") => None,
+            ("glue", "registry") => Some(format!(
+                "https://{region}.{domain}/glue/home?region={region}#/v2/data-catalog/schema-registries/view/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("glue", "schema") => {
+                let (registry_name, schema_name) = self.resource_id().split_once('/')?;
+                Some(format!(
+                    "https://{region}.{domain}/glue/home?region={region}#/v2/data-catalog/schemas/view/{schema_name}?registryName={registry_name}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    schema_name = schema_name,
+                    registry_name = registry_name,
+                ))
+            }
+            ("glue", "table") => {
+                let (database_name, table_name) = self.resource_id().split_once('/')?;
+                Some(format!(
+                    "https://{region}.{domain}/glue/home?region={region}#/v2/data-catalog/tables/view/{table_name}?database={database_name}&catalogId={account}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    table_name = table_name,
+                    database_name = database_name,
+                    account = self.account(),
+                ))
+            }
             // ("glue", "tableversion") => None,
-            // ("glue", "trigger") => None,
+            ("glue", "trigger") => Some(format!(
+                "https://{region}.{domain}/glue/home?region={region}#/v2/etl-configuration/triggers/view/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("glue", "userdefinedfunction") => None,
-            // ("glue", "workflow") => None,
+            ("glue", "workflow") => Some(format!(
+                "https://{region}.{domain}/glue/home?region={region}#/v2/etl-configuration/workflows/view/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // Amazon Managed Service for Grafana
             // ("grafana", "workspace") => None,
@@ -891,8 +1746,18 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("greengrass", "connectorDefinitionVersion") => None,
             // ("greengrass", "coreDefinition") => None,
             // ("greengrass", "coreDefinitionVersion") => None,
-            // ("greengrass", "coreDevice") => None,
-            // ("greengrass", "deployment") => None,
+            ("greengrass", "coreDevices") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/greengrass/v2/cores/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("greengrass", "deployments") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/greengrass/v2/deployments/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("greengrass", "deviceDefinition") => None,
             // ("greengrass", "deviceDefinitionVersion") => None,
             // ("greengrass", "functionDefinition") => None,
@@ -916,24 +1781,27 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("groundstation", "Satellite") => None,
 
             // Amazon GuardDuty
-            // ("guardduty", "detector") => None,
-            // ("guardduty", "filter") => None,
-            // ("guardduty", "ipset") => None,
-            // ("guardduty", "publishingDestination") => None,
-            // ("guardduty", "threatintelset") => None,
+            ("guardduty", "detector") => {
+                let parts: Vec<&str> = self.resource_id().split('/').collect();
+                match parts.as_slice() {
+                    [_detector_id, "finding", finding_id] => Some(format!(
+                        "https://{region}.{domain}/guardduty/home?region={region}#/findings?macros=current&fId={finding_id}",
+                        region = self.region(),
+                        domain = self.domain()?,
+                        finding_id = finding_id,
+                    )),
+                    // The detector itself, filters, IP sets, threat intel sets and publishing
+                    // destinations (`<detector-id>/ipset/<id>` and so on) are not linked yet.
+                    _ => None,
+                }
+            }
 
             // AWS Health APIs and Notifications
             // ("health", "event") => None,
 
-            // Amazon Honeycode
-            // ("honeycode", "screen") => None,
-            // ("honeycode", "screen-automation") => None,
-            // ("honeycode", "table") => None,
-            // ("honeycode", "workbook") => None,
-
             // AWS Identity and Access Management
             // ("iam", "access-report") => None,
-            // ("iam", "assumed-role") => None,
+            // Assumed roles have STS ARNs, see ("sts", "assumed-role").
             // ("iam", "federated-user") => None,
             ("iam", "group") => Some(format!(
                 "https://{domain}/iamv2/home#/groups/details/{last}",
@@ -957,6 +1825,11 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
                 domain = self.domain()?,
                 last = self.path_last(),
             )),
+            ("iam", "saml-provider") => Some(format!(
+                "https://{domain}/iam/home?#/providers/{string}",
+                domain = self.domain()?,
+                string = self.build(),
+            )),
             // ("iam", "server-certificate") => None,
             // ("iam", "sms-mfa") => None,
             ("iam", "user") => Some(format!(
@@ -976,52 +1849,166 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("imagebuilder", "imageVersion") => None,
             // ("imagebuilder", "infrastructureConfiguration") => None,
 
-            // AWS IoT Greengrass
-            // ("iot", "authorizer") => None,
-            // ("iot", "billinggroup") => None,
-            // ("iot", "cacert") => None,
-            // ("iot", "cert") => None,
+            // Amazon Inspector
+            ("inspector2", "finding") => Some(format!(
+                "https://{region}.{domain}/inspector/v2/home?region={region}#/findings/all?findingArn={arn}",
+                region = self.region(),
+                domain = self.domain()?,
+                arn = self.encoded_arn(),
+            )),
+
+            // AWS IoT Core
+            ("iot", "authorizer") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/authorizer/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("iot", "billinggroup") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/billinggroup/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("iot", "cacert") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/cacertificate/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("iot", "cert") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/certificate/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("iot", "client") => None,
             // ("iot", "dimension") => None,
-            // ("iot", "domainconfiguration") => None,
-            // ("iot", "dynamicthinggroup") => None,
+            ("iot", "domainconfiguration") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/domainconfiguration/{name}",
+                region = self.region(),
+                domain = self.domain()?,
+                name = self.resource_id().split('/').next()?,
+            )),
+            ("iot", "dynamicthinggroup") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/thinggroup/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("iot", "fleetmetric") => None,
             // ("iot", "index") => None,
-            // ("iot", "job") => None,
+            ("iot", "job") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/job/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("iot", "mitigationaction") => None,
             // ("iot", "otaupdate") => None,
-            // ("iot", "policy") => None,
-            // ("iot", "provisioningtemplate") => None,
-            // ("iot", "rolealias") => None,
-            // ("iot", "rule") => None,
+            ("iot", "policy") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/policy/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("iot", "provisioningtemplate") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/provisioningtemplate/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("iot", "rolealias") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/rolealias/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("iot", "rule") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/rule/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("iot", "scheduledaudit") => None,
-            // ("iot", "securityprofile") => None,
+            ("iot", "securityprofile") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/dd/securityProfile/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("iot", "stream") => None,
-            // ("iot", "thing") => None,
-            // ("iot", "thinggroup") => None,
-            // ("iot", "thingtype") => None,
+            ("iot", "thing") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/thing/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("iot", "thinggroup") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/thinggroup/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("iot", "thingtype") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/thingtype/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("iot", "topic") => None,
             // ("iot", "topicfilter") => None,
             // ("iot", "tunnel") => None,
 
-            // AWS IoT 1-Click
-            // ("iot1click", "device") => None,
-            // ("iot1click", "project") => None,
-
             // AWS IoT Analytics
-            // ("iotanalytics", "channel") => None,
-            // ("iotanalytics", "dataset") => None,
-            // ("iotanalytics", "datastore") => None,
-            // ("iotanalytics", "pipeline") => None,
+            ("iotanalytics", "channel") => Some(format!(
+                "https://{region}.{domain}/iotanalytics/home?region={region}#/channels/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("iotanalytics", "dataset") => Some(format!(
+                "https://{region}.{domain}/iotanalytics/home?region={region}#/datasets/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("iotanalytics", "datastore") => Some(format!(
+                "https://{region}.{domain}/iotanalytics/home?region={region}#/datastores/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("iotanalytics", "pipeline") => Some(format!(
+                "https://{region}.{domain}/iotanalytics/home?region={region}#/pipelines/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // AWS IoT Core Device Advisor
             // ("iotdeviceadvisor", "suitedefinition") => None,
             // ("iotdeviceadvisor", "suiterun") => None,
 
             // AWS IoT Events
-            // ("iotevents", "alarmModel") => None,
-            // ("iotevents", "detectorModel") => None,
-            // ("iotevents", "input") => None,
+            ("iotevents", "alarmModel") => Some(format!(
+                "https://{region}.{domain}/iotevents/home?region={region}#/alarmmodel/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("iotevents", "detectorModel") => Some(format!(
+                "https://{region}.{domain}/iotevents/home?region={region}#/detectormodel/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("iotevents", "input") => Some(format!(
+                "https://{region}.{domain}/iotevents/home?region={region}#/input/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // Fleet Hub for AWS IoT Device Management
             // ("iotfleethub", "application") => None,
@@ -1029,32 +2016,72 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
 
             // AWS IoT SiteWise
             // ("iotsitewise", "access-policy") => None,
-            // ("iotsitewise", "asset") => None,
-            // ("iotsitewise", "asset-model") => None,
+            ("iotsitewise", "asset") => Some(format!(
+                "https://{region}.{domain}/iotsitewise/home?region={region}#/assets/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("iotsitewise", "asset-model") => Some(format!(
+                "https://{region}.{domain}/iotsitewise/home?region={region}#/asset-models/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("iotsitewise", "dashboard") => None,
-            // ("iotsitewise", "gateway") => None,
-            // ("iotsitewise", "portal") => None,
+            ("iotsitewise", "gateway") => Some(format!(
+                "https://{region}.{domain}/iotsitewise/home?region={region}#/gateways/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("iotsitewise", "portal") => Some(format!(
+                "https://{region}.{domain}/iotsitewise/home?region={region}#/portals/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("iotsitewise", "project") => None,
 
-            // AWS IoT Things Graph
-            // ("iotthingsgraph", "System") => None,
-            // ("iotthingsgraph", "SystemInstance") => None,
-            // ("iotthingsgraph", "Workflow") => None,
-
             // AWS IoT Core for LoRaWAN
-            // ("iotwireless", "Destination") => None,
+            ("iotwireless", "Destination") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/wireless/destinations/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("iotwireless", "DeviceProfile") => None,
             // ("iotwireless", "ServiceProfile") => None,
-            // ("iotwireless", "WirelessDevice") => None,
-            // ("iotwireless", "WirelessGateway") => None,
+            ("iotwireless", "WirelessDevice") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/wireless/devices/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("iotwireless", "WirelessGateway") => Some(format!(
+                "https://{region}.{domain}/iot/home?region={region}#/wireless/gateways/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // Amazon Interactive Video Service
-            // ("ivs", "Channel") => None,
-            // ("ivs", "Playback-Key-Pair") => None,
-            // ("ivs", "Stream-Key") => None,
+            ("ivs", "channel") => Some(format!(
+                "https://{region}.{domain}/ivs/home?region={region}#/channels/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            // ("ivs", "playback-key-pair") => None,
+            // ("ivs", "stream-key") => None,
 
             // Amazon Managed Streaming for Apache Kafka
-            // ("kafka", "cluster") => None,
+            ("kafka", "cluster") => Some(format!(
+                "https://{region}.{domain}/msk/home?region={region}#/cluster/{arn}/view",
+                region = self.region(),
+                domain = self.domain()?,
+                arn = self.encoded_arn(),
+            )),
 
             // Amazon Kendra
             // ("kendra", "data-source") => None,
@@ -1064,17 +2091,38 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
 
             // Amazon Kinesis
             // ("kinesis", "consumer") => None,
-            // ("kinesis", "stream") => None,
+            // Enhanced fan-out consumers (`stream/name/consumer/...`) link to their stream.
+            ("kinesis", "stream") => Some(format!(
+                "https://{region}.{domain}/kinesis/home?region={region}#/streams/details/{stream_name}/monitoring",
+                region = self.region(),
+                domain = self.domain()?,
+                stream_name = self.resource_id().split('/').next()?,
+            )),
 
             // Amazon Kinesis Analytics V2
             // ("kinesisanalytics", "application") => None,
 
             // Amazon Kinesis Video Streams
-            // ("kinesisvideo", "channel") => None,
-            // ("kinesisvideo", "stream") => None,
+            ("kinesisvideo", "channel") => Some(format!(
+                "https://{region}.{domain}/kinesisvideo/home?region={region}#/signalingChannels/channelName/{channel_name}",
+                region = self.region(),
+                domain = self.domain()?,
+                channel_name = self.resource_id().split('/').next()?,
+            )),
+            ("kinesisvideo", "stream") => Some(format!(
+                "https://{region}.{domain}/kinesisvideo/home?region={region}#/streams/streamName/{stream_name}",
+                region = self.region(),
+                domain = self.domain()?,
+                stream_name = self.resource_id().split('/').next()?,
+            )),
 
             // AWS Key Management Service
-            // ("kms", "alias") => None,
+            ("kms", "alias") => Some(format!(
+                "https://{domain}/kms/home?region={region}#/kms/keys/alias/{resource}",
+                domain = self.domain()?,
+                region = self.region(),
+                resource = self.resource_id(),
+            )),
             ("kms", "key") => Some(format!(
                 "https://{domain}/kms/home?region={region}#/kms/keys/{resource}",
                 domain = self.domain()?,
@@ -1084,30 +2132,59 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("kms", "kmsKey") => None,
 
             // AWS Lambda
-            // ("lambda", "code signing config") => None,
-            // ("lambda", "eventSourceMapping") => None,
-            ("lambda", "function") => Some(format!(
-                "https://{region}.{domain}/lambda/home?region={region}#/functions/{resource}",
+            ("lambda", "code-signing-config") => Some(format!(
+                "https://{region}.{domain}/lambda/home?region={region}#/code-signing-configurations/{resource}",
                 region = self.region(),
                 domain = self.domain()?,
                 resource = self.resource_id(),
             )),
-            // ("lambda", "function alias") => None,
-            // ("lambda", "function version") => None,
-            ("lambda", "layer") => {
-                let (qualifier0, qualifier1) = match self.resource_id().split_once(':') {
-                    Some((a, "")) => (a, "1"),
-                    Some((a, b)) => (a, b),
-                    None => (self.resource_id(), "1"),
-                };
-                Some(format!(
-                    "https://{region}.{domain}/lambda/home?region={region}#/layers/{qualifier0}/versions/{qualifier1}",
+            ("lambda", "event-source-mapping") => Some(format!(
+                "https://{region}.{domain}/lambda/home?region={region}#/eventSourceMappings/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            // Qualified function ARNs end in a version number or an alias name.
+            ("lambda", "function") => match self.resource_id().split_once(':') {
+                None | Some((_, "")) => Some(format!(
+                    "https://{region}.{domain}/lambda/home?region={region}#/functions/{resource}",
                     region = self.region(),
                     domain = self.domain()?,
-                    qualifier0 = qualifier0,
-                    qualifier1 = qualifier1,
-                ))
-            }
+                    resource = self.resource_id().trim_end_matches(':'),
+                )),
+                Some((function_name, version)) if version.chars().all(|c| c.is_ascii_digit()) => {
+                    Some(format!(
+                        "https://{region}.{domain}/lambda/home?region={region}#/functions/{function_name}/versions/{version}",
+                        region = self.region(),
+                        domain = self.domain()?,
+                        function_name = function_name,
+                        version = version,
+                    ))
+                }
+                Some((function_name, alias)) => Some(format!(
+                    "https://{region}.{domain}/lambda/home?region={region}#/functions/{function_name}/aliases/{alias}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    function_name = function_name,
+                    alias = alias,
+                )),
+            },
+            ("lambda", "layer") => match self.resource_id().split_once(':') {
+                Some((name, version)) if !version.is_empty() => Some(format!(
+                    "https://{region}.{domain}/lambda/home?region={region}#/layers/{name}/versions/{version}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    name = name,
+                    version = version,
+                )),
+                // Without a version, link to the layer itself; older versions may be deleted.
+                _ => Some(format!(
+                    "https://{region}.{domain}/lambda/home?region={region}#/layers/{name}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    name = self.resource_id().trim_end_matches(':'),
+                )),
+            },
             // ("lambda", "layerVersion") => None,
 
             // Amazon Lex V2
@@ -1158,10 +2235,6 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("lookoutequipment", "inference-scheduler") => None,
             // ("lookoutequipment", "model") => None,
 
-            // Amazon Lookout for Vision
-            // ("lookoutvision", "model") => None,
-            // ("lookoutvision", "project") => None,
-
             // Amazon Machine Learning
             // ("machinelearning", "batchprediction") => None,
             // ("machinelearning", "datasource") => None,
@@ -1169,10 +2242,15 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("machinelearning", "mlmodel") => None,
 
             // Amazon Macie
-            // ("macie2", "ClassificationJob") => None,
-            // ("macie2", "CustomDataIdentifier") => None,
-            // ("macie2", "FindingsFilter") => None,
-            // ("macie2", "Member") => None,
+            ("macie2", "classification-job") => Some(format!(
+                "https://{region}.{domain}/macie/home?region={region}#jobs?jobId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            // ("macie2", "custom-data-identifier") => None,
+            // ("macie2", "findings-filter") => None,
+            // ("macie2", "member") => None,
 
             // Amazon Managed Blockchain
             // ("managedblockchain", "invitation") => None,
@@ -1188,11 +2266,31 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("mediaconnect", "Source") => None,
 
             // AWS Elemental MediaConvert
-            // ("mediaconvert", "CertificateAssociation") => None,
-            // ("mediaconvert", "Job") => None,
-            // ("mediaconvert", "JobTemplate") => None,
-            // ("mediaconvert", "Preset") => None,
-            // ("mediaconvert", "Queue") => None,
+            // ("mediaconvert", "certificates") => None,
+            ("mediaconvert", "jobTemplates") => Some(format!(
+                "https://{region}.{domain}/mediaconvert/home?region={region}#/templates/details/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("mediaconvert", "jobs") => Some(format!(
+                "https://{region}.{domain}/mediaconvert/home?region={region}#/jobs/summary/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("mediaconvert", "presets") => Some(format!(
+                "https://{region}.{domain}/mediaconvert/home?region={region}#/presets/details/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("mediaconvert", "queues") => Some(format!(
+                "https://{region}.{domain}/mediaconvert/home?region={region}#/queues/details/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // AWS Elemental MediaLive
             ("medialive", "channel") => Some(format!(
@@ -1201,54 +2299,141 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
                 domain = self.domain()?,
                 resource = self.resource_id(),
             )),
-            // ("medialive", "input") => None,
-            // ("medialive", "input-device") => None,
-            // ("medialive", "input-security-group") => None,
-            // ("medialive", "multiplex") => None,
+            ("medialive", "input") => Some(format!(
+                "https://{region}.{domain}/medialive/home?region={region}#/inputs/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            // ("medialive", "inputDevice") => None,
+            ("medialive", "inputSecurityGroup") => Some(format!(
+                "https://{region}.{domain}/medialive/home?region={region}#/input-security-groups/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("medialive", "multiplex") => Some(format!(
+                "https://{region}.{domain}/medialive/home?region={region}#/multiplexes/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("medialive", "offering") => None,
             // ("medialive", "reservation") => None,
 
             // AWS Elemental MediaPackage
+            // These ARNs end in a generated hash rather than the id the console uses.
             // ("mediapackage", "channels") => None,
             // ("mediapackage", "harvest_jobs") => None,
             // ("mediapackage", "origin_endpoints") => None,
 
             // AWS Elemental MediaPackage VOD
-            // ("mediapackage-vod", "assets") => None,
+            ("mediapackage-vod", "assets") => Some(format!(
+                "https://{region}.{domain}/mediapackagevod/home?region={region}#/assets/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("mediapackage-vod", "packaging-configurations") => None,
-            // ("mediapackage-vod", "packaging-groups") => None,
+            ("mediapackage-vod", "packaging-groups") => Some(format!(
+                "https://{region}.{domain}/mediapackagevod/home?region={region}#/packaging-groups/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
-            // AWS Elemental MediaStore
-            // ("mediastore", "container") => None,
+            // AWS Elemental MediaPackage v2
+            ("mediapackagev2", "channelGroup") => {
+                let parts: Vec<&str> = self.resource_id().split('/').collect();
+                match parts.as_slice() {
+                    [group_name, "channel", channel_name, ..] => Some(format!(
+                        "https://{region}.{domain}/mediapackagev2/home?region={region}#/channelGroups/{group_name}/channels/{channel_name}",
+                        region = self.region(),
+                        domain = self.domain()?,
+                        group_name = group_name,
+                        channel_name = channel_name,
+                    )),
+                    _ => Some(format!(
+                        "https://{region}.{domain}/mediapackagev2/home?region={region}#/channelGroups/{group_name}",
+                        region = self.region(),
+                        domain = self.domain()?,
+                        group_name = parts[0],
+                    )),
+                }
+            }
 
             // AWS Elemental MediaTailor
-            // ("mediatailor", "playbackConfiguration") => None,
+            ("mediatailor", "playbackConfiguration") => Some(format!(
+                "https://{region}.{domain}/mediatailor/home?region={region}#/configurations/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+
+            // Amazon MemoryDB
+            ("memorydb", "cluster") => Some(format!(
+                "https://{region}.{domain}/memorydb/home?region={region}#/clusters/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // AWS Migration Hub
-            // ("mgh", "migrationTask") => None,
+            // Migration tasks are only shown through the applications and servers they touch.
             // ("mgh", "progressUpdateStream") => None,
 
-            // AWS Mobile Hub
-            // ("mobilehub", "project") => None,
-
             // Amazon Pinpoint
-            // ("mobiletargeting", "apps") => None,
-            // ("mobiletargeting", "campaigns") => None,
-            // ("mobiletargeting", "journeys") => None,
+            // Campaigns, journeys and segments are nested under their project.
+            ("mobiletargeting", "apps") => {
+                let parts: Vec<&str> = self.resource_id().split('/').collect();
+                match parts.as_slice() {
+                    [app_id, kind @ ("campaigns" | "journeys" | "segments"), id] => Some(format!(
+                        "https://{region}.{domain}/pinpoint/home?region={region}#/apps/{app_id}/{kind}/{id}",
+                        region = self.region(),
+                        domain = self.domain()?,
+                        app_id = app_id,
+                        kind = kind,
+                        id = id,
+                    )),
+                    _ => Some(format!(
+                        "https://{region}.{domain}/pinpoint/home?region={region}#/apps/{app_id}/dashboard",
+                        region = self.region(),
+                        domain = self.domain()?,
+                        app_id = parts[0],
+                    )),
+                }
+            }
             // ("mobiletargeting", "recommenders") => None,
-            // ("mobiletargeting", "segments") => None,
             // ("mobiletargeting", "templates") => None,
 
             // Amazon Monitron
             // ("monitron", "project") => None,
 
             // Amazon MQ
-            // ("mq", "brokers") => None,
-            // ("mq", "configurations") => None,
+            ("mq", "broker") => Some(format!(
+                "https://{region}.{domain}/amazon-mq/home?region={region}#/brokers/details?id={broker_id}",
+                region = self.region(),
+                domain = self.domain()?,
+                broker_id = self.resource_id().split_once(':')?.1,
+            )),
+            ("mq", "configuration") => Some(format!(
+                "https://{region}.{domain}/amazon-mq/home?region={region}#/configurations/details?id={configuration_id}",
+                region = self.region(),
+                domain = self.domain()?,
+                configuration_id = self.resource_id().split_once(':')?.1,
+            )),
 
             // Amazon Neptune
             // ("neptune-db", "database") => None,
 
+            // Amazon Neptune Analytics
+            ("neptune-graph", "graph") => Some(format!(
+                "https://{region}.{domain}/neptune/home?region={region}#graphs/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+
             // AWS Network Firewall
             // ("network-firewall", "Firewall") => None,
             // ("network-firewall", "FirewallPolicy") => None,
@@ -1298,6 +2483,14 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // AWS Performance Insights
             // ("pi", "metric-resource") => None,
 
+            // Amazon EventBridge Pipes
+            ("pipes", "pipe") => Some(format!(
+                "https://{region}.{domain}/events/home?region={region}#/pipes/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+
             // Amazon Polly
             // ("polly", "lexicon") => None,
 
@@ -1317,9 +2510,13 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("proton", "service-template-major-version") => None,
             // ("proton", "service-template-minor-version") => None,
 
-            // Amazon QLDB
-            // ("qldb", "ledger") => None,
-            // ("qldb", "stream") => None,
+            // Amazon Q Business
+            ("qbusiness", "application") => Some(format!(
+                "https://{region}.{domain}/amazonq/business/applications/{resource}?region={region}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // Amazon QuickSight
             // ("quicksight", "analysis") => None,
@@ -1341,6 +2538,8 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("ram", "resource-share-invitation") => None,
 
             // Amazon RDS
+            // DocumentDB and Neptune clusters share this namespace, but the ARN
+            // doesn't say which engine a cluster runs. The RDS console lists them all.
             ("rds", "cluster") => Some(format!(
                 "https://{domain}/rds/home?region={region}#database:id={resource};is-cluster=true",
                 domain = self.domain()?,
@@ -1387,21 +2586,45 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("rds-db", "db-user") => None,
 
             // Amazon Redshift
-            // ("redshift", "cluster") => None,
+            ("redshift", "cluster") => Some(format!(
+                "https://{region}.{domain}/redshiftv2/home?region={region}#cluster-details?cluster={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("redshift", "dbgroup") => None,
             // ("redshift", "dbname") => None,
             // ("redshift", "dbuser") => None,
             // ("redshift", "eventsubscription") => None,
             // ("redshift", "hsmclientcertificate") => None,
             // ("redshift", "hsmconfiguration") => None,
-            // ("redshift", "parametergroup") => None,
+            ("redshift", "parametergroup") => Some(format!(
+                "https://{region}.{domain}/redshiftv2/home?region={region}#parameter-group-details?parameterGroupName={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("redshift", "securitygroup") => None,
             // ("redshift", "securitygroupingress-cidr") => None,
             // ("redshift", "securitygroupingress-ec2securitygroup") => None,
-            // ("redshift", "snapshot") => None,
+            ("redshift", "snapshot") => {
+                let (cluster_name, snapshot_name) = self.resource_id().split_once('/')?;
+                Some(format!(
+                    "https://{region}.{domain}/redshiftv2/home?region={region}#snapshot-details?snapshot={snapshot_name}&cluster={cluster_name}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    snapshot_name = snapshot_name,
+                    cluster_name = cluster_name,
+                ))
+            }
             // ("redshift", "snapshotcopygrant") => None,
             // ("redshift", "snapshotschedule") => None,
-            // ("redshift", "subnetgroup") => None,
+            ("redshift", "subnetgroup") => Some(format!(
+                "https://{region}.{domain}/redshiftv2/home?region={region}#subnet-group-details?subnetGroupName={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // Amazon Rekognition
             // ("rekognition", "collection") => None,
@@ -1409,6 +2632,14 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("rekognition", "projectversion") => None,
             // ("rekognition", "streamprocessor") => None,
 
+            // AWS Resilience Hub
+            ("resiliencehub", "app") => Some(format!(
+                "https://{region}.{domain}/resiliencehub/home?region={region}#/applications/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+
             // AWS Resource Groups
             // ("resource-groups", "group") => None,
 
@@ -1450,10 +2681,37 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             )),
 
             // Amazon Route 53 Resolver
+            ("route53resolver", "firewall-domain-list") => Some(format!(
+                "https://{region}.{domain}/route53resolver/home?region={region}#/firewall/domain-lists/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("route53resolver", "firewall-rule-group") => Some(format!(
+                "https://{region}.{domain}/route53resolver/home?region={region}#/firewall/rule-groups/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("route53resolver", "resolver-dnssec-config") => None,
-            // ("route53resolver", "resolver-endpoint") => None,
-            // ("route53resolver", "resolver-query-log-config") => None,
-            // ("route53resolver", "resolver-rule") => None,
+            ("route53resolver", "resolver-endpoint") => Some(format!(
+                "https://{region}.{domain}/route53resolver/home?region={region}#/endpoint/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("route53resolver", "resolver-query-log-config") => Some(format!(
+                "https://{region}.{domain}/route53resolver/home?region={region}#/query-logging/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("route53resolver", "resolver-rule") => Some(format!(
+                "https://{region}.{domain}/route53resolver/home?region={region}#/rule/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // Amazon Simple Storage Service (S3)
             ("s3", "") => Some(format!(
@@ -1461,35 +2719,139 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
                 domain = self.domain()?,
                 resource = self.resource_id(),
             )),
-            // ("s3", "accesspoint") => None,
-            // ("s3", "bucket") => None,
-            // ("s3", "job") => None,
-            // ("s3", "object") => None,
-            // ("s3", "storagelensconfiguration") => None,
+            // Access points, jobs and Storage Lens have a region and account,
+            // which sets them apart from objects in equally named buckets.
+            ("s3", "accesspoint") if !self.region().is_empty() => Some(format!(
+                "https://s3.{domain}/s3/accesspoint/{name}?region={region}",
+                domain = self.domain()?,
+                name = self.resource_id().split('/').next()?,
+                region = self.region(),
+            )),
+            // Multi-Region Access Points have an account, but no region.
+            ("s3", "accesspoint") if !self.account().is_empty() => Some(format!(
+                "https://s3.{domain}/s3/mraccesspoint/{account}/{alias}",
+                domain = self.domain()?,
+                account = self.account(),
+                alias = self.resource_id(),
+            )),
+            ("s3", "job") if !self.region().is_empty() => Some(format!(
+                "https://s3.{domain}/s3/jobs/{resource}?region={region}",
+                domain = self.domain()?,
+                resource = self.resource_id(),
+                region = self.region(),
+            )),
+            // Objects are parsed with the bucket name as the resource type.
+            ("s3", bucket) if self.account().is_empty() && self.has_path() => {
+                // The parser splits keys on their first colon, so stitch them back together.
+                let key = match self.resource_revision() {
+                    "" => self.resource_id().to_string(),
+                    revision => format!("{}:{}", self.resource_id(), revision),
+                };
+                if key.is_empty() {
+                    return None;
+                }
+                // The console keeps slashes in the prefix as they are.
+                let prefix = key
+                    .split('/')
+                    .map(percent_encode)
+                    .collect::<Vec<_>>()
+                    .join("/");
+                if key.ends_with('/') {
+                    Some(format!(
+                        "https://s3.{domain}/s3/buckets/{bucket}?prefix={prefix}",
+                        domain = self.domain()?,
+                        bucket = bucket,
+                        prefix = prefix,
+                    ))
+                } else {
+                    Some(format!(
+                        "https://s3.{domain}/s3/object/{bucket}?prefix={prefix}",
+                        domain = self.domain()?,
+                        bucket = bucket,
+                        prefix = prefix,
+                    ))
+                }
+            }
+            ("s3", "storage-lens") if !self.region().is_empty() => Some(format!(
+                "https://s3.{domain}/s3/lens/dashboard/{resource}?region={region}",
+                domain = self.domain()?,
+                resource = self.resource_id(),
+                region = self.region(),
+            )),
+
+            // Amazon S3 Object Lambda
+            ("s3-object-lambda", "accesspoint") => Some(format!(
+                "https://s3.{domain}/s3/olap/{account}/{resource}/configuration?region={region}",
+                domain = self.domain()?,
+                account = self.account(),
+                resource = self.resource_id(),
+                region = self.region(),
+            )),
 
             // Amazon S3 on Outposts
-            // ("s3-outposts", "accesspoint") => None,
-            // ("s3-outposts", "bucket") => None,
-            // ("s3-outposts", "endpoint") => None,
-            // ("s3-outposts", "object") => None,
+            ("s3-outposts", "outpost") => {
+                let parts: Vec<&str> = self.resource_id().split('/').collect();
+                match parts.as_slice() {
+                    [outpost_id, "accesspoint", accesspoint_name] => Some(format!(
+                        "https://s3.{domain}/s3/outposts/{outpost_id}/accesspoints/{accesspoint_name}?region={region}",
+                        domain = self.domain()?,
+                        outpost_id = outpost_id,
+                        accesspoint_name = accesspoint_name,
+                        region = self.region(),
+                    )),
+                    [outpost_id, "bucket", bucket_name, ..] => Some(format!(
+                        "https://s3.{domain}/s3/outposts/{outpost_id}/buckets/{bucket_name}?region={region}",
+                        domain = self.domain()?,
+                        outpost_id = outpost_id,
+                        bucket_name = bucket_name,
+                        region = self.region(),
+                    )),
+                    _ => None,
+                }
+            }
 
             // Amazon SageMaker
             // ("sagemaker", "action") => None,
-            // ("sagemaker", "algorithm") => None,
+            ("sagemaker", "algorithm") => Some(format!(
+                "https://{region}.{domain}/sagemaker/home?region={region}#/algorithms/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("sagemaker", "app") => None,
             // ("sagemaker", "app-image-config") => None,
             // ("sagemaker", "artifact") => None,
             // ("sagemaker", "automl-job") => None,
             // ("sagemaker", "code-repository") => None,
-            // ("sagemaker", "compilation-job") => None,
+            ("sagemaker", "compilation-job") => Some(format!(
+                "https://{region}.{domain}/sagemaker/home?region={region}#/compilation-jobs/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("sagemaker", "context") => None,
             // ("sagemaker", "data-quality-job-definition") => None,
             // ("sagemaker", "device") => None,
             // ("sagemaker", "device-fleet") => None,
-            // ("sagemaker", "domain") => None,
+            ("sagemaker", "domain") => Some(format!(
+                "https://{region}.{domain}/sagemaker/home?region={region}#/studio/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("sagemaker", "edge-packaging-job") => None,
-            // ("sagemaker", "endpoint") => None,
-            // ("sagemaker", "endpoint-config") => None,
+            ("sagemaker", "endpoint") => Some(format!(
+                "https://{region}.{domain}/sagemaker/home?region={region}#/endpoints/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("sagemaker", "endpoint-config") => Some(format!(
+                "https://{region}.{domain}/sagemaker/home?region={region}#/endpointConfig/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("sagemaker", "experiment") => None,
             // ("sagemaker", "experiment-trial") => None,
             // ("sagemaker", "experiment-trial-component") => None,
@@ -1497,36 +2859,124 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("sagemaker", "flow-definition") => None,
             // ("sagemaker", "human-loop") => None,
             // ("sagemaker", "human-task-ui") => None,
-            // ("sagemaker", "hyper-parameter-tuning-job") => None,
+            ("sagemaker", "hyper-parameter-tuning-job") => Some(format!(
+                "https://{region}.{domain}/sagemaker/home?region={region}#/hyper-tuning-jobs/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("sagemaker", "image") => None,
             // ("sagemaker", "image-version") => None,
-            // ("sagemaker", "labeling-job") => None,
-            // ("sagemaker", "model") => None,
+            ("sagemaker", "labeling-job") => Some(format!(
+                "https://{region}.{domain}/sagemaker/home?region={region}#/labeling-jobs/details/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("sagemaker", "model") => Some(format!(
+                "https://{region}.{domain}/sagemaker/home?region={region}#/models/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("sagemaker", "model-bias-job-definition") => None,
             // ("sagemaker", "model-explainability-job-definition") => None,
-            // ("sagemaker", "model-package") => None,
+            // Versioned packages (`<group>/<version>`) and pipelines only show up in
+            // Studio, whose URL depends on the domain rather than the ARN.
+            ("sagemaker", "model-package") if !self.resource_id().contains('/') => Some(format!(
+                "https://{region}.{domain}/sagemaker/home?region={region}#/model-packages/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("sagemaker", "model-package-group") => None,
             // ("sagemaker", "model-quality-job-definition") => None,
             // ("sagemaker", "monitoring-schedule") => None,
-            // ("sagemaker", "notebook-instance") => None,
-            // ("sagemaker", "notebook-instance-lifecycle-config") => None,
+            ("sagemaker", "notebook-instance") => Some(format!(
+                "https://{region}.{domain}/sagemaker/home?region={region}#/notebook-instances/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("sagemaker", "notebook-instance-lifecycle-config") => Some(format!(
+                "https://{region}.{domain}/sagemaker/home?region={region}#/notebook-instance-lifecycle-configs/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("sagemaker", "pipeline") => None,
             // ("sagemaker", "pipeline-execution") => None,
-            // ("sagemaker", "processing-job") => None,
+            ("sagemaker", "processing-job") => Some(format!(
+                "https://{region}.{domain}/sagemaker/home?region={region}#/processing-jobs/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("sagemaker", "project") => None,
-            // ("sagemaker", "training-job") => None,
-            // ("sagemaker", "transform-job") => None,
-            // ("sagemaker", "user-profile") => None,
+            ("sagemaker", "training-job") => Some(format!(
+                "https://{region}.{domain}/sagemaker/home?region={region}#/jobs/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("sagemaker", "transform-job") => Some(format!(
+                "https://{region}.{domain}/sagemaker/home?region={region}#/transform-jobs/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("sagemaker", "user-profile") => {
+                let (domain_id, user_profile_name) = self.resource_id().split_once('/')?;
+                Some(format!(
+                    "https://{region}.{domain}/sagemaker/home?region={region}#/studio/{domain_id}/user/{user_profile_name}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    domain_id = domain_id,
+                    user_profile_name = user_profile_name,
+                ))
+            }
             // ("sagemaker", "workforce") => None,
             // ("sagemaker", "workteam") => None,
 
             // AWS Savings Plans
             // ("savingsplans", "savingsplan") => None,
 
+            // Amazon EventBridge Scheduler
+            ("scheduler", "schedule") => {
+                let (group_name, schedule_name) = self.resource_id().split_once('/')?;
+                Some(format!(
+                    "https://{region}.{domain}/scheduler/home?region={region}#schedules/{group_name}/{schedule_name}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    group_name = group_name,
+                    schedule_name = schedule_name,
+                ))
+            }
+            ("scheduler", "schedule-group") => Some(format!(
+                "https://{region}.{domain}/scheduler/home?region={region}#schedule-groups/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+
             // Amazon EventBridge Schemas
             // ("schemas", "discoverer") => None,
-            // ("schemas", "registry") => None,
-            // ("schemas", "schema") => None,
+            ("schemas", "registry") => Some(format!(
+                "https://{region}.{domain}/events/home?region={region}#/registries/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("schemas", "schema") => {
+                let (registry_name, schema_name) = self.resource_id().split_once('/')?;
+                Some(format!(
+                    "https://{region}.{domain}/events/home?region={region}#/registries/{registry_name}/schemas/{schema_name}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    registry_name = registry_name,
+                    schema_name = schema_name,
+                ))
+            }
 
             // Amazon SimpleDB
             // ("sdb", "domain") => None,
@@ -1542,13 +2992,35 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
                     region = self.region(),
                     domain = self.domain()?,
                     service = self.service(),
-                    name = name,
+                    name = percent_encode(name),
                 ))
             }
 
             // AWS Security Hub
             // ("securityhub", "hub") => None,
             // ("securityhub", "product") => None,
+            // Control findings are identified by an ARN below the standard's subscription.
+            ("securityhub", "subscription") if self.resource_id().contains("/finding/") => {
+                Some(format!(
+                    "https://{region}.{domain}/securityhub/home?region={region}#/findings?search=Id%3D%255Coperator%255C%253AEQUALS%255C%253A{arn}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    arn = percent_encode(&self.encoded_arn()),
+                ))
+            }
+
+            // Amazon Security Lake
+            ("securitylake", "data-lake") => Some(format!(
+                "https://{region}.{domain}/securitylake/home?region={region}#/summary",
+                region = self.region(),
+                domain = self.domain()?,
+            )),
+            ("securitylake", "subscriber") => Some(format!(
+                "https://{region}.{domain}/securitylake/home?region={region}#/subscribers/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // AWS Serverless Application Repository
             // ("serverlessrepo", "applications") => None,
@@ -1565,16 +3037,36 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("servicequotas", "quota") => None,
 
             // Amazon SES
-            // ("ses", "configuration-set") => None,
+            ("ses", "configuration-set") => Some(format!(
+                "https://{region}.{domain}/ses/home?region={region}#/configuration-sets/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("ses", "custom-verification-email-template") => None,
-            // ("ses", "dedicated-ip-pool") => None,
+            ("ses", "dedicated-ip-pool") => Some(format!(
+                "https://{region}.{domain}/ses/home?region={region}#/dedicated-ips/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("ses", "deliverability-test-report") => None,
             // ("ses", "event-destination") => None,
-            // ("ses", "identity") => None,
+            ("ses", "identity") => Some(format!(
+                "https://{region}.{domain}/ses/home?region={region}#/identities/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = percent_encode(self.resource_id()),
+            )),
             // ("ses", "receipt-filter") => None,
             // ("ses", "receipt-rule") => None,
             // ("ses", "receipt-rule-set") => None,
-            // ("ses", "template") => None,
+            ("ses", "template") => Some(format!(
+                "https://{region}.{domain}/ses/home?region={region}#/email-templates/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // AWS Shield
             // ("shield", "attack") => None,
@@ -1584,6 +3076,26 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("signer", "signing-job") => None,
             // ("signer", "signing-profile") => None,
 
+            // AWS End User Messaging SMS
+            ("sms-voice", "configuration-set") => Some(format!(
+                "https://{region}.{domain}/sms-voice/home?region={region}#/configuration-sets/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("sms-voice", "phone-number") => Some(format!(
+                "https://{region}.{domain}/sms-voice/home?region={region}#/phone-numbers/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("sms-voice", "pool") => Some(format!(
+                "https://{region}.{domain}/sms-voice/home?region={region}#/pools/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+
             // Amazon SNS
             ("sns", "") => Some(format!(
                 "https://{domain}/sns/v3/home?region={region}#/topic/{arn}",
@@ -1591,6 +3103,13 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
                 region = self.region(),
                 arn = self.build(),
             )),
+            // Subscriptions are parsed with the topic name as the resource type.
+            ("sns", _) if !self.has_path() => Some(format!(
+                "https://{domain}/sns/v3/home?region={region}#/subscription/{arn}",
+                domain = self.domain()?,
+                region = self.region(),
+                arn = self.build(),
+            )),
 
             // Amazon SQS
             ("sqs", "") => Some(format!(
@@ -1620,18 +3139,55 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("ssm", "windowtask") => None,
 
             // AWS SSO
-            // ("sso", "Account") => None,
-            // ("sso", "Instance") => None,
-            // ("sso", "PermissionSet") => None,
+            // ("sso", "account") => None,
+            ("sso", "instance") => Some(format!(
+                "https://{domain}/singlesignon/home",
+                domain = self.domain()?,
+            )),
+            ("sso", "permissionSet") => {
+                let (instance_id, permission_set_id) = self.resource_id().split_once('/')?;
+                Some(format!(
+                    "https://{domain}/singlesignon/organization/home#/instances/{instance_id}/permission-sets/details/{permission_set_id}",
+                    domain = self.domain()?,
+                    instance_id = instance_id.strip_prefix("ssoins-")?,
+                    permission_set_id = permission_set_id,
+                ))
+            }
+
+            // AWS Security Token Service
+            // Assumed role sessions link to the role they were assumed from.
+            ("sts", "assumed-role") => Some(format!(
+                "https://{domain}/iam/home?#/roles/{role_name}",
+                domain = self.domain()?,
+                role_name = self.resource_id().split('/').next()?,
+            )),
+            // ("sts", "federated-user") => None,
 
             // AWS Step Functions
-            // ("states", "activity") => None,
+            ("states", "activity") => Some(format!(
+                "https://{region}.{domain}/states/home?region={region}#/activities/details/{arn}",
+                region = self.region(),
+                domain = self.domain()?,
+                arn = self.build(),
+            )),
             ("states", "execution") => Some(format!(
                 "https://{region}.{domain}/states/home?region={region}#/v2/executions/details/{string}",
                 region = self.region(),
                 domain = self.domain()?,
                 string = self.build(),
             )),
+            ("states", "express") => Some(format!(
+                "https://{region}.{domain}/states/home?region={region}#/express-executions/details/{string}",
+                region = self.region(),
+                domain = self.domain()?,
+                string = self.build(),
+            )),
+            ("states", "mapRun") => Some(format!(
+                "https://{region}.{domain}/states/home?region={region}#/v2/map-run/details/{string}",
+                region = self.region(),
+                domain = self.domain()?,
+                string = self.build(),
+            )),
             ("states", "stateMachine") => Some(format!(
                 "https://{region}.{domain}/states/home?region={region}#/statemachines/view/{string}",
                 region = self.region(),
@@ -1641,15 +3197,37 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
 
             // Amazon Storage Gateway
             // ("storagegateway", "device") => None,
-            // ("storagegateway", "gateway") => None,
-            // ("storagegateway", "share") => None,
-            // ("storagegateway", "tape") => None,
+            ("storagegateway", "gateway") => {
+                let parts: Vec<&str> = self.resource_id().split('/').collect();
+                match parts.as_slice() {
+                    [gateway_id] => Some(format!(
+                        "https://{region}.{domain}/storagegateway/home?region={region}#/gateways/{gateway_id}",
+                        region = self.region(),
+                        domain = self.domain()?,
+                        gateway_id = gateway_id,
+                    )),
+                    [_gateway_id, "volume", volume_id] => Some(format!(
+                        "https://{region}.{domain}/storagegateway/home?region={region}#/volumes/{volume_id}",
+                        region = self.region(),
+                        domain = self.domain()?,
+                        volume_id = volume_id,
+                    )),
+                    _ => None,
+                }
+            }
+            ("storagegateway", "share") => Some(format!(
+                "https://{region}.{domain}/storagegateway/home?region={region}#/file-shares/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("storagegateway", "tape") => Some(format!(
+                "https://{region}.{domain}/storagegateway/home?region={region}#/tapes/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
             // ("storagegateway", "tapepool") => None,
-            // ("storagegateway", "target") => None,
-            // ("storagegateway", "volume") => None,
-
-            // Amazon Sumerian
-            // ("sumerian", "project") => None,
 
             // Amazon Simple Workflow Service
             // ("swf", "domain") => None,
@@ -1662,12 +3240,40 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // ("timestream", "table") => None,
 
             // AWS Transfer for SFTP
-            // ("transfer", "server") => None,
-            // ("transfer", "user") => None,
+            ("transfer", "server") => Some(format!(
+                "https://{region}.{domain}/transfer/home?region={region}#/servers/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("transfer", "user") => {
+                let (server_id, user_name) = self.resource_id().split_once('/')?;
+                Some(format!(
+                    "https://{region}.{domain}/transfer/home?region={region}#/servers/{server_id}/users/{user_name}",
+                    region = self.region(),
+                    domain = self.domain()?,
+                    server_id = server_id,
+                    user_name = user_name,
+                ))
+            }
 
             // AWS Trusted Advisor
             // ("trustedadvisor", "checks") => None,
 
+            // Amazon VPC Lattice
+            ("vpc-lattice", "service") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#ServiceDetails:serviceId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("vpc-lattice", "servicenetwork") => Some(format!(
+                "https://{region}.{domain}/vpcconsole/home?region={region}#ServiceNetworkDetails:serviceNetworkId={resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+
             // AWS WAF
             // ("waf", "bytematchset") => None,
             // ("waf", "geomatchset") => None,
@@ -1716,20 +3322,42 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
             // AWS Well-Architected Tool
             // ("wellarchitected", "workload") => None,
 
-            // Amazon WorkLink
-            // ("worklink", "fleet") => None,
-
             // Amazon WorkMail
-            // ("workmail", "organization") => None,
+            ("workmail", "organization") => Some(format!(
+                "https://{region}.{domain}/workmail/v2/home?region={region}#/organizations/details/{resource}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // Amazon WorkMail Message Flow
             // ("workmailmessageflow", "RawMessage") => None,
 
             // Amazon WorkSpaces
-            // ("workspaces", "directoryid") => None,
-            // ("workspaces", "workspacebundle") => None,
-            // ("workspaces", "workspaceid") => None,
-            // ("workspaces", "workspaceipgroup") => None,
+            ("workspaces", "directory") => Some(format!(
+                "https://{region}.{domain}/workspaces/v2/directories/{resource}?region={region}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("workspaces", "workspace") => Some(format!(
+                "https://{region}.{domain}/workspaces/v2/workspaces/{resource}?region={region}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("workspaces", "workspacebundle") => Some(format!(
+                "https://{region}.{domain}/workspaces/v2/bundles/{resource}?region={region}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
+            ("workspaces", "workspaceipgroup") => Some(format!(
+                "https://{region}.{domain}/workspaces/v2/ip-access-controls/{resource}?region={region}",
+                region = self.region(),
+                domain = self.domain()?,
+                resource = self.resource_id(),
+            )),
 
             // AWS X-Ray
             // ("xray", "group") => None,
@@ -1745,6 +3373,7 @@ pub trait ArnParts<'a>: ArnPartsHelper<'a> {
 trait ArnPartsHelper<'a> {
     fn domain(&self) -> Option<&str>;
     fn path_last(&self) -> &str;
+    fn encoded_arn(&self) -> String;
 }
 
 impl<'a, T: ArnParts<'a>> ArnPartsHelper<'a> for T {
@@ -1786,4 +3415,29 @@ impl<'a, T: ArnParts<'a>> ArnPartsHelper<'a> for T {
             self.resource_id()
         }
     }
+
+    /// Returns the ARN percent-encoded, for consoles that take it as a
+    /// query value or a single path segment.
+    fn encoded_arn(&self) -> String {
+        percent_encode(&self.build())
+    }
+}
+
+/// Percent-encodes everything except the unreserved characters of RFC 3986.
+///
+/// ```text
+/// Input: "arn:aws:sns:us-east-1:123456789012:topic/a+b"
+/// Output: "arn%3Aaws%3Asns%3Aus-east-1%3A123456789012%3Atopic%2Fa%2Bb"
+/// ```
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(char::from(byte));
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
 }
@@ -126,6 +126,7 @@ fn run_tests(json_str: &str) {
     let cases = cases.as_object().unwrap();
 
     // Run each test case.
+    #[allow(clippy::result_large_err)]
     let results = cases.into_iter().map(|(input, expected)| {
         match expected {
             // A string means the input should lead to that console link.